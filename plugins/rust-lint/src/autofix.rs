@@ -0,0 +1,107 @@
+//! Apply clippy's machine-applicable suggestions and report the result as a
+//! diff, rather than mutating fixtures in place.
+//!
+//! This consumes the `suggestions` already gathered on
+//! [`crate::diagnostics::Diagnostic`] — a single diagnostic can carry more
+//! than one machine-applicable span (e.g. `needless_return` rewrites the
+//! expression and drops the trailing `;` separately), so every span across
+//! every diagnostic is applied, not just one per diagnostic.
+
+use similar::TextDiff;
+
+use crate::diagnostics::Diagnostic;
+
+/// Apply every machine-applicable replacement across `diagnostics` to
+/// `source`, back-to-front so earlier byte offsets stay valid. A replacement
+/// whose span overlaps one already applied is skipped rather than corrupting
+/// the source.
+pub fn apply_suggestions(source: &str, diagnostics: &[Diagnostic]) -> String {
+    let mut replacements: Vec<_> = diagnostics.iter().flat_map(|d| &d.suggestions).collect();
+    replacements.sort_by_key(|r| std::cmp::Reverse(r.byte_start));
+
+    let mut result = source.to_string();
+    let mut last_applied_start = usize::MAX;
+    for replacement in replacements {
+        let start = replacement.byte_start as usize;
+        let end = replacement.byte_end as usize;
+        if end > last_applied_start {
+            continue;
+        }
+        result.replace_range(start..end, &replacement.text);
+        last_applied_start = start;
+    }
+    result
+}
+
+/// Render a unified diff between `original` and `fixed`, labeling the hunks
+/// with `file_name` the way `git diff` would.
+pub fn unified_diff(file_name: &str, original: &str, fixed: &str) -> String {
+    TextDiff::from_lines(original, fixed)
+        .unified_diff()
+        .header(file_name, file_name)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::{LintCategory, Replacement, Severity};
+
+    fn diagnostic(suggestions: Vec<Replacement>) -> Diagnostic {
+        Diagnostic {
+            lint: "clippy::unnecessary_cast".to_string(),
+            category: LintCategory::Style,
+            severity: Severity::Warning,
+            file: "src/clippy_errors.rs".to_string(),
+            line: 1,
+            column: 1,
+            message: "casting to the same type is unnecessary".to_string(),
+            byte_start: 0,
+            byte_end: 0,
+            suggestions,
+        }
+    }
+
+    fn replacement(byte_start: u32, byte_end: u32, text: &str) -> Replacement {
+        Replacement {
+            byte_start,
+            byte_end,
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn applies_single_suggestion() {
+        let source = "x as i32";
+        let fixed = apply_suggestions(source, &[diagnostic(vec![replacement(0, 8, "x")])]);
+        assert_eq!(fixed, "x");
+    }
+
+    #[test]
+    fn applies_non_overlapping_suggestions_back_to_front() {
+        let source = "let a = x as i32; let b = y as i32;";
+        let diagnostics = vec![
+            diagnostic(vec![replacement(8, 16, "x")]),
+            diagnostic(vec![replacement(26, 34, "y")]),
+        ];
+        let fixed = apply_suggestions(source, &diagnostics);
+        assert_eq!(fixed, "let a = x; let b = y;");
+    }
+
+    #[test]
+    fn applies_multiple_spans_from_a_single_diagnostic() {
+        // needless_return-shaped: one diagnostic, two spans (rewrite the
+        // expression, then drop the now-redundant trailing `;`).
+        let source = "return x + 1;";
+        let diagnostics = vec![diagnostic(vec![replacement(0, 12, "x + 1"), replacement(12, 13, "")])];
+        let fixed = apply_suggestions(source, &diagnostics);
+        assert_eq!(fixed, "x + 1");
+    }
+
+    #[test]
+    fn diff_shows_the_change() {
+        let diff = unified_diff("src/clippy_errors.rs", "x as i32", "x");
+        assert!(diff.contains("-x as i32"));
+        assert!(diff.contains("+x"));
+    }
+}
@@ -0,0 +1,285 @@
+//! Structured clippy diagnostics.
+//!
+//! `cargo clippy --message-format=json` emits one JSON object per line. This
+//! module runs that command over a fixture crate and turns the raw stream
+//! into typed [`Diagnostic`]s grouped by clippy's own lint category, so
+//! callers can do things like "3 style, 1 correctness" summaries instead of
+//! scanning a flat warning dump.
+
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use serde::Deserialize;
+
+use crate::profile::LintProfile;
+
+/// Severity clippy assigned to a diagnostic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
+impl Severity {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "error" => Severity::Error,
+            "warning" => Severity::Warning,
+            "help" => Severity::Help,
+            _ => Severity::Note,
+        }
+    }
+}
+
+/// The broad clippy lint groups (see `clippy::lint_groups`). `Allow` is what
+/// [`categorize`] reports for any lint missing from its seed table, whatever
+/// group clippy itself puts that lint in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintCategory {
+    Correctness,
+    Style,
+    Complexity,
+    Perf,
+    Pedantic,
+    Nursery,
+    Allow,
+}
+
+impl LintCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintCategory::Correctness => "correctness",
+            LintCategory::Style => "style",
+            LintCategory::Complexity => "complexity",
+            LintCategory::Perf => "perf",
+            LintCategory::Pedantic => "pedantic",
+            LintCategory::Nursery => "nursery",
+            LintCategory::Allow => "allow",
+        }
+    }
+}
+
+/// One parsed clippy diagnostic.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    /// Fully qualified lint name, e.g. `clippy::unnecessary_cast`.
+    pub lint: String,
+    pub category: LintCategory,
+    pub severity: Severity,
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+    pub message: String,
+    /// Byte offsets of the diagnostic's own primary span in `file`.
+    pub byte_start: u32,
+    pub byte_end: u32,
+    /// Machine-applicable replacements clippy offered, each with its own
+    /// byte range — a single lint can need more than one edit (e.g.
+    /// `needless_return` rewrites the expression *and* drops the trailing
+    /// `;` as separate spans). See [`crate::autofix`].
+    pub suggestions: Vec<Replacement>,
+}
+
+/// One machine-applicable edit: replace `source[byte_start..byte_end]` with
+/// `text`.
+#[derive(Debug, Clone)]
+pub struct Replacement {
+    pub byte_start: u32,
+    pub byte_end: u32,
+    pub text: String,
+}
+
+/// Look up the clippy category for a bare lint name (without the `clippy::`
+/// prefix). Seeded from clippy's historical lint list; unknown lints are
+/// reported as `Allow` since that's the safe default for a lint we don't
+/// recognize yet.
+pub fn categorize(lint_name: &str) -> LintCategory {
+    use LintCategory::*;
+    match lint_name {
+        "cmp_nan" => Correctness,
+        "approx_constant" => Style,
+        "box_vec" => Style,
+        "unnecessary_cast" => Style,
+        "single_match" => Style,
+        "needless_return" => Style,
+        "too_many_arguments" => Complexity,
+        "type_complexity" => Complexity,
+        "cloned_instead_of_copied" => Perf,
+        "redundant_clone" => Perf,
+        "cast_possible_truncation" => Pedantic,
+        "cast_possible_wrap" => Pedantic,
+        "cast_precision_loss" => Pedantic,
+        "cast_sign_loss" => Pedantic,
+        "missing_docs_in_private_items" => Pedantic,
+        "doc_markdown" => Pedantic,
+        "manual_let_else" => Pedantic,
+        "explicit_iter_loop" => Pedantic,
+        "semicolon_if_nothing_returned" => Pedantic,
+        "float_cmp" => Pedantic,
+        "float_cmp_const" => Pedantic,
+        "redundant_pub_crate" => Nursery,
+        "use_self" => Nursery,
+        _ => Allow,
+    }
+}
+
+/// Raw shape of a `cargo clippy --message-format=json` line. Only the fields
+/// we care about are modeled; everything else is dropped.
+#[derive(Deserialize)]
+struct CargoMessage {
+    reason: String,
+    message: Option<ClippyMessage>,
+}
+
+#[derive(Deserialize)]
+struct ClippyMessage {
+    code: Option<ClippyCode>,
+    level: String,
+    message: String,
+    spans: Vec<ClippySpan>,
+    #[serde(default)]
+    children: Vec<ClippyChild>,
+}
+
+/// A suggestion is rendered as a nested `help` message with its own spans,
+/// not as a field on the diagnostic's own primary span.
+#[derive(Deserialize)]
+struct ClippyChild {
+    #[serde(default)]
+    spans: Vec<ClippySpan>,
+}
+
+#[derive(Deserialize)]
+struct ClippyCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct ClippySpan {
+    file_name: String,
+    line_start: u32,
+    column_start: u32,
+    byte_start: u32,
+    byte_end: u32,
+    is_primary: bool,
+    suggested_replacement: Option<String>,
+    suggestion_applicability: Option<String>,
+}
+
+/// Run `cargo clippy --message-format=json` in `manifest_dir`, with
+/// `profile`'s extra flags appended after `--` (the same place a CI pipeline
+/// would put `-W`/`-D` overrides), and parse the output into [`Diagnostic`]s.
+/// Non-lint cargo messages (build scripts, `compiler-artifact`, etc.) are
+/// skipped.
+pub fn run_clippy(manifest_dir: &Path, profile: &LintProfile) -> io::Result<Vec<Diagnostic>> {
+    let mut command = Command::new("cargo");
+    command
+        .arg("clippy")
+        .arg("--message-format=json")
+        .current_dir(manifest_dir);
+    if !profile.extra_flags.is_empty() {
+        command.arg("--").args(&profile.extra_flags);
+    }
+    let output = command.output()?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Ok(parse_clippy_json(&stdout))
+}
+
+/// Parse already-captured `cargo clippy --message-format=json` output. Split
+/// out from [`run_clippy`] so tests can feed it canned JSON without shelling
+/// out to cargo.
+pub fn parse_clippy_json(json_lines: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for line in json_lines.lines() {
+        let Ok(msg) = serde_json::from_str::<CargoMessage>(line) else {
+            continue;
+        };
+        if msg.reason != "compiler-message" {
+            continue;
+        }
+        let Some(message) = msg.message else {
+            continue;
+        };
+        let Some(code) = message.code else {
+            continue;
+        };
+        let Some(bare_lint) = code.code.strip_prefix("clippy::") else {
+            continue;
+        };
+        let category = categorize(bare_lint);
+        let lint = code.code.clone();
+        let Some(span) = message.spans.iter().find(|s| s.is_primary) else {
+            continue;
+        };
+        // Machine-applicable suggestions live on child "help" messages' own
+        // spans, which also carry the byte ranges the replacements apply to
+        // (they may differ from, and there may be more than one per, the
+        // primary span — e.g. `needless_return` rewrites the expression and
+        // drops the trailing `;` as two separate spans).
+        let suggestions = message
+            .children
+            .iter()
+            .flat_map(|child| &child.spans)
+            .filter(|s| s.suggestion_applicability.as_deref() == Some("MachineApplicable"))
+            .filter_map(|s| {
+                s.suggested_replacement.clone().map(|text| Replacement {
+                    byte_start: s.byte_start,
+                    byte_end: s.byte_end,
+                    text,
+                })
+            })
+            .collect();
+        diagnostics.push(Diagnostic {
+            category,
+            lint,
+            severity: Severity::from_str(&message.level),
+            file: span.file_name.clone(),
+            line: span.line_start,
+            column: span.column_start,
+            message: message.message,
+            byte_start: span.byte_start,
+            byte_end: span.byte_end,
+            suggestions,
+        });
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn categorizes_known_lints() {
+        assert_eq!(categorize("cmp_nan").as_str(), "correctness");
+        assert_eq!(categorize("approx_constant").as_str(), "style");
+        assert_eq!(categorize("cast_possible_truncation").as_str(), "pedantic");
+    }
+
+    #[test]
+    fn unknown_lint_defaults_to_allow() {
+        assert_eq!(categorize("some_future_lint").as_str(), "allow");
+    }
+
+    #[test]
+    fn parses_compiler_message_into_diagnostic() {
+        let json = r#"{"reason":"compiler-message","message":{"code":{"code":"clippy::unnecessary_cast"},"level":"warning","message":"casting to the same type is unnecessary","spans":[{"file_name":"src/clippy_errors.rs","line_start":5,"column_start":5,"byte_start":40,"byte_end":48,"is_primary":true,"suggested_replacement":null,"suggestion_applicability":null}],"children":[{"spans":[{"file_name":"src/clippy_errors.rs","line_start":5,"column_start":5,"byte_start":40,"byte_end":48,"is_primary":true,"suggested_replacement":"x","suggestion_applicability":"MachineApplicable"}]}]}}"#;
+        let diagnostics = parse_clippy_json(json);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].lint, "clippy::unnecessary_cast");
+        assert_eq!(diagnostics[0].category.as_str(), "style");
+        assert_eq!(diagnostics[0].line, 5);
+        assert_eq!(diagnostics[0].suggestions.len(), 1);
+        assert_eq!(diagnostics[0].suggestions[0].text, "x");
+    }
+
+    #[test]
+    fn ignores_non_lint_messages() {
+        let json = r#"{"reason":"compiler-artifact"}"#;
+        assert!(parse_clippy_json(json).is_empty());
+    }
+}
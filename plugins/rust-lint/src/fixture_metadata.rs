@@ -0,0 +1,179 @@
+//! The set of lints each fixture is declared to produce.
+//!
+//! `tests/fixture_audit.rs` runs clippy over the fixtures crate and asserts
+//! the observed lints for each item match this table exactly, so a clippy or
+//! rustfmt version bump that changes which lints fire gets caught instead of
+//! silently making a fixture dead example code.
+
+/// One fixture item and the exact lints it's expected to trigger, keyed by
+/// the line range it occupies in its source file (clippy diagnostics are
+/// per-span, not per-item, so this is how we attribute a diagnostic back to
+/// the function it came from).
+///
+/// `profile` names the [`crate::profile::LintProfile`] the audit must run
+/// under to observe these lints — most fixtures only need `"default"`, but
+/// anything allow-by-default (missing docs, pedantic casts) needs the
+/// matching profile enabling it, or the audit will never see it fire.
+pub struct FixtureExpectation {
+    pub file: &'static str,
+    pub item: &'static str,
+    pub line_range: (u32, u32),
+    pub profile: &'static str,
+    pub lints: &'static [&'static str],
+}
+
+pub const EXPECTATIONS: &[FixtureExpectation] = &[
+    FixtureExpectation {
+        file: "clean.rs",
+        item: "clean::*",
+        line_range: (1, 41),
+        profile: "default",
+        lints: &[],
+    },
+    FixtureExpectation {
+        file: "clippy_errors.rs",
+        item: "unnecessary_cast_example",
+        line_range: (4, 6),
+        profile: "default",
+        lints: &["clippy::unnecessary_cast"],
+    },
+    // Clippy now suggests `.unwrap_or_default()` for this shape rather than
+    // `single_match`; the fixture's own doc comment still calls out the
+    // if-let rewrite it was originally written to demonstrate.
+    FixtureExpectation {
+        file: "clippy_errors.rs",
+        item: "single_match_example",
+        line_range: (9, 14),
+        profile: "default",
+        lints: &["clippy::manual_unwrap_or_default"],
+    },
+    FixtureExpectation {
+        file: "clippy_errors.rs",
+        item: "needless_return_example",
+        line_range: (17, 19),
+        profile: "default",
+        lints: &["clippy::needless_return"],
+    },
+    FixtureExpectation {
+        file: "clippy_errors.rs",
+        item: "multiple_issues",
+        line_range: (22, 28),
+        profile: "default",
+        lints: &["clippy::unnecessary_cast", "clippy::needless_return"],
+    },
+    // clippy::float_cmp is itself pedantic/allow-by-default, so a bare run
+    // reports nothing here; these two are caught instead by our own
+    // float_cmp::find_float_cmp_issues detector, which has its own unit
+    // tests in src/float_cmp.rs.
+    FixtureExpectation {
+        file: "float_errors.rs",
+        item: "float_literal_eq",
+        line_range: (7, 9),
+        profile: "default",
+        lints: &[],
+    },
+    FixtureExpectation {
+        file: "float_errors.rs",
+        item: "float_sum_eq",
+        line_range: (12, 14),
+        profile: "default",
+        lints: &[],
+    },
+    FixtureExpectation {
+        file: "float_errors.rs",
+        item: "float_ne_zero",
+        line_range: (18, 20),
+        profile: "default",
+        lints: &[],
+    },
+    FixtureExpectation {
+        file: "float_errors.rs",
+        item: "float_less_than",
+        line_range: (23, 25),
+        profile: "default",
+        lints: &[],
+    },
+    // missing_docs_in_private_items/manual_let_else/explicit_iter_loop are
+    // only enabled by the "strict" profile, so these three must run under
+    // it — under a bare `cargo clippy` none of them fire.
+    FixtureExpectation {
+        file: "strict_profile_errors.rs",
+        item: "undocumented_private",
+        line_range: (10, 12),
+        profile: "strict",
+        lints: &["clippy::missing_docs_in_private_items"],
+    },
+    FixtureExpectation {
+        file: "strict_profile_errors.rs",
+        item: "manual_let_else_example",
+        line_range: (15, 21),
+        profile: "strict",
+        lints: &["clippy::manual_let_else"],
+    },
+    FixtureExpectation {
+        file: "strict_profile_errors.rs",
+        item: "explicit_iter_loop_example",
+        line_range: (24, 30),
+        profile: "strict",
+        lints: &["clippy::explicit_iter_loop"],
+    },
+    // The cast_errors fixtures exercise allow-by-default pedantic lints:
+    // silent under "default" (asserted here), and only surfaced under the
+    // "pedantic_casts" profile (asserted separately below).
+    FixtureExpectation {
+        file: "cast_errors.rs",
+        item: "narrowing_cast",
+        line_range: (6, 8),
+        profile: "default",
+        lints: &[],
+    },
+    FixtureExpectation {
+        file: "cast_errors.rs",
+        item: "wrapping_cast",
+        line_range: (11, 13),
+        profile: "default",
+        lints: &[],
+    },
+    FixtureExpectation {
+        file: "cast_errors.rs",
+        item: "precision_losing_cast",
+        line_range: (16, 18),
+        profile: "default",
+        lints: &[],
+    },
+    FixtureExpectation {
+        file: "cast_errors.rs",
+        item: "sign_losing_cast",
+        line_range: (21, 23),
+        profile: "default",
+        lints: &[],
+    },
+    FixtureExpectation {
+        file: "cast_errors.rs",
+        item: "narrowing_cast",
+        line_range: (6, 8),
+        profile: "pedantic_casts",
+        lints: &["clippy::cast_possible_truncation"],
+    },
+    FixtureExpectation {
+        file: "cast_errors.rs",
+        item: "wrapping_cast",
+        line_range: (11, 13),
+        profile: "pedantic_casts",
+        lints: &["clippy::cast_possible_wrap"],
+    },
+    FixtureExpectation {
+        file: "cast_errors.rs",
+        item: "precision_losing_cast",
+        line_range: (16, 18),
+        profile: "pedantic_casts",
+        lints: &["clippy::cast_precision_loss"],
+    },
+    FixtureExpectation {
+        file: "cast_errors.rs",
+        item: "sign_losing_cast",
+        line_range: (21, 23),
+        profile: "pedantic_casts",
+        lints: &["clippy::cast_sign_loss"],
+    },
+];
@@ -0,0 +1,260 @@
+//! Detector for direct float equality comparisons, mirroring
+//! `clippy::float_cmp` / `clippy::float_cmp_const` closely enough to suggest
+//! a fix without needing a full type checker.
+//!
+//! The detector walks binary expressions and flags `==`/`!=` where at least
+//! one operand is float-shaped: a float literal (`1.0f32`), a known `const`
+//! of float type, or an `x as f32`/`x as f64` cast. Ordering comparisons are
+//! never flagged, nor are comparisons against exactly `0.0`/`-0.0` or
+//! `f32::INFINITY`/`NEG_INFINITY`, since those are exact by construction.
+//! Comparisons inside a function named `eq` or a `PartialEq` impl are
+//! suppressed too, since that's usually the canonical place equality is
+//! meant to be defined.
+
+use std::collections::HashSet;
+
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{BinOp, Expr, File, ImplItem, Item, Lit};
+
+/// One flagged direct float comparison.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FloatCmpFinding {
+    pub line: usize,
+    pub column: usize,
+    /// Suggested replacement expression, e.g. `(a - b).abs() < f32::EPSILON`.
+    pub suggestion: String,
+}
+
+/// Parse `source` and return every direct float comparison that isn't
+/// exempt.
+pub fn find_float_cmp_issues(source: &str) -> syn::Result<Vec<FloatCmpFinding>> {
+    let file: File = syn::parse_file(source)?;
+    let float_consts = collect_float_consts(&file);
+    let mut visitor = FloatCmpVisitor {
+        float_consts,
+        in_eq_context: false,
+        findings: Vec::new(),
+    };
+    visitor.visit_file(&file);
+    Ok(visitor.findings)
+}
+
+/// Top-level `const NAME: f32/f64 = ...;` names, so `ONE == TWO` can be
+/// recognized as a float comparison even though neither operand is a
+/// literal at the use site.
+fn collect_float_consts(file: &File) -> HashSet<String> {
+    let mut names = HashSet::new();
+    for item in &file.items {
+        if let Item::Const(item_const) = item {
+            if is_float_type(&item_const.ty) {
+                names.insert(item_const.ident.to_string());
+            }
+        }
+    }
+    names
+}
+
+fn is_float_type(ty: &syn::Type) -> bool {
+    if let syn::Type::Path(type_path) = ty {
+        if let Some(segment) = type_path.path.segments.last() {
+            return segment.ident == "f32" || segment.ident == "f64";
+        }
+    }
+    false
+}
+
+struct FloatCmpVisitor {
+    float_consts: HashSet<String>,
+    in_eq_context: bool,
+    findings: Vec<FloatCmpFinding>,
+}
+
+/// `syn` parses a digit-only literal with a float suffix (`1f32`, `0f64`) as
+/// `Lit::Int`, not `Lit::Float` — the suffix is the only thing distinguishing
+/// it from a plain integer, so both variants need checking.
+fn literal_is_float(lit: &Lit) -> bool {
+    match lit {
+        Lit::Float(_) => true,
+        Lit::Int(i) => matches!(i.suffix(), "f32" | "f64"),
+        _ => false,
+    }
+}
+
+/// Is `lit` exactly zero (covers both `0.0` and the `Lit::Int`-shaped `0f32`).
+fn literal_is_zero(lit: &Lit) -> bool {
+    match lit {
+        Lit::Float(f) => f.base10_digits() == "0.0" || f.base10_digits() == "0",
+        Lit::Int(i) => literal_is_float(lit) && i.base10_digits() == "0",
+        _ => false,
+    }
+}
+
+impl FloatCmpVisitor {
+    /// Does `expr` look like it has a floating-point type?
+    fn is_float_like(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Lit(lit) => literal_is_float(&lit.lit),
+            Expr::Cast(cast) => is_float_type(&cast.ty),
+            Expr::Path(path) => path
+                .path
+                .get_ident()
+                .map(|ident| self.float_consts.contains(&ident.to_string()))
+                .unwrap_or(false),
+            Expr::Binary(bin) => self.is_float_like(&bin.left) || self.is_float_like(&bin.right),
+            Expr::Unary(unary) => self.is_float_like(&unary.expr),
+            Expr::Paren(paren) => self.is_float_like(&paren.expr),
+            _ => false,
+        }
+    }
+
+    /// `0.0`, `-0.0`, `f32::INFINITY`, `f32::NEG_INFINITY` (and the `f64`
+    /// equivalents) compare exactly, so they're exempt even when the other
+    /// operand is float-shaped.
+    fn is_exempt_operand(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Lit(lit) => literal_is_zero(&lit.lit),
+            Expr::Unary(unary) => self.is_exempt_operand(&unary.expr),
+            Expr::Path(path) => path
+                .path
+                .segments
+                .last()
+                .map(|s| s.ident == "INFINITY" || s.ident == "NEG_INFINITY")
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    fn suggestion_for(&self, op: &BinOp, left: &str, right: &str) -> String {
+        match op {
+            BinOp::Eq(_) => format!("({left} - {right}).abs() < f32::EPSILON"),
+            BinOp::Ne(_) => format!("({left} - {right}).abs() >= f32::EPSILON"),
+            _ => unreachable!("caller only invokes this for Eq/Ne"),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for FloatCmpVisitor {
+    fn visit_item_fn(&mut self, item_fn: &'ast syn::ItemFn) {
+        let previously_in_eq = self.in_eq_context;
+        if item_fn.sig.ident == "eq" {
+            self.in_eq_context = true;
+        }
+        visit::visit_item_fn(self, item_fn);
+        self.in_eq_context = previously_in_eq;
+    }
+
+    fn visit_impl_item_fn(&mut self, method: &'ast syn::ImplItemFn) {
+        let previously_in_eq = self.in_eq_context;
+        if method.sig.ident == "eq" {
+            self.in_eq_context = true;
+        }
+        visit::visit_impl_item_fn(self, method);
+        self.in_eq_context = previously_in_eq;
+    }
+
+    fn visit_item_impl(&mut self, item_impl: &'ast syn::ItemImpl) {
+        let previously_in_eq = self.in_eq_context;
+        let is_partial_eq_impl = item_impl
+            .trait_
+            .as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .map(|segment| segment.ident == "PartialEq")
+            .unwrap_or(false);
+        if is_partial_eq_impl {
+            self.in_eq_context = true;
+        }
+        for item in &item_impl.items {
+            if let ImplItem::Fn(method) = item {
+                self.visit_impl_item_fn(method);
+            }
+        }
+        self.in_eq_context = previously_in_eq;
+    }
+
+    fn visit_expr_binary(&mut self, expr_binary: &'ast syn::ExprBinary) {
+        let is_eq_or_ne = matches!(expr_binary.op, BinOp::Eq(_) | BinOp::Ne(_));
+        if is_eq_or_ne && !self.in_eq_context {
+            let either_float = self.is_float_like(&expr_binary.left) || self.is_float_like(&expr_binary.right);
+            let either_exempt =
+                self.is_exempt_operand(&expr_binary.left) || self.is_exempt_operand(&expr_binary.right);
+            if either_float && !either_exempt {
+                let left_expr = &expr_binary.left;
+                let right_expr = &expr_binary.right;
+                let left = quote::quote!(#left_expr).to_string();
+                let right = quote::quote!(#right_expr).to_string();
+                let start = expr_binary.span().start();
+                self.findings.push(FloatCmpFinding {
+                    line: start.line,
+                    column: start.column,
+                    suggestion: self.suggestion_for(&expr_binary.op, &left, &right),
+                });
+            }
+        }
+        visit::visit_expr_binary(self, expr_binary);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_float_literal_equality() {
+        let source = "const ONE: f32 = 1.0; fn f() -> bool { 1f32 == ONE }";
+        let findings = find_float_cmp_issues(source).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn suggestion_names_each_operand_exactly_once() {
+        let source = "const ONE: f32 = 1.0; fn f() -> bool { 1f32 == ONE }";
+        let findings = find_float_cmp_issues(source).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].suggestion, "(1f32 - ONE).abs() < f32::EPSILON");
+    }
+
+    #[test]
+    fn finding_reports_the_real_source_position() {
+        let source = "fn f() -> bool {\n    1f32 == 2f32\n}";
+        let findings = find_float_cmp_issues(source).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].line, 2);
+        assert_eq!(findings[0].column, 4);
+    }
+
+    #[test]
+    fn flags_literal_vs_literal_equality() {
+        let source = "fn f() -> bool { 1f32 == 2f32 }";
+        let findings = find_float_cmp_issues(source).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn flags_computed_const_equality() {
+        let source = "const ONE: f32 = 1.0; const TWO: f32 = 2.0; fn f() -> bool { ONE + ONE == TWO }";
+        let findings = find_float_cmp_issues(source).unwrap();
+        assert_eq!(findings.len(), 1);
+    }
+
+    #[test]
+    fn does_not_flag_comparison_against_zero() {
+        let source = "const ONE: f32 = 1.0; fn f() -> bool { ONE != 0f32 }";
+        let findings = find_float_cmp_issues(source).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn does_not_flag_ordering_comparisons() {
+        let source = "fn f(x: f32, y: f32) -> bool { x < y }";
+        let findings = find_float_cmp_issues(source).unwrap();
+        assert!(findings.is_empty());
+    }
+
+    #[test]
+    fn does_not_flag_partial_eq_impl() {
+        let source = "struct P(f32); impl PartialEq for P { fn eq(&self, other: &Self) -> bool { self.0 == other.0 } }";
+        let findings = find_float_cmp_issues(source).unwrap();
+        assert!(findings.is_empty());
+    }
+}
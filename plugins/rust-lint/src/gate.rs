@@ -0,0 +1,109 @@
+//! Severity-aware gating: decide which reported diagnostics should fail a
+//! run versus merely be surfaced, so normally-silent lints (the pedantic
+//! cast family, nursery, etc.) can be audited on demand without permanently
+//! denying lints the compiler allows by default.
+//!
+//! A policy only acts on diagnostics it's handed — the pedantic cast lints
+//! are allow-by-default in clippy itself, so they won't show up at all
+//! unless the caller also ran [`crate::diagnostics::run_clippy`] with
+//! [`crate::profile::LintProfile::pedantic_casts`] (or an equivalent
+//! profile enabling those specific lints).
+
+use crate::diagnostics::{Diagnostic, LintCategory};
+
+/// Which categories cause a non-zero exit. Correctness always does;
+/// pedantic/nursery only count toward the gate when explicitly escalated.
+#[derive(Debug, Clone, Copy)]
+pub struct GatePolicy {
+    pub escalate_pedantic: bool,
+    pub escalate_nursery: bool,
+}
+
+impl GatePolicy {
+    /// Fail only on correctness (clippy's own deny-by-default group).
+    pub fn default_policy() -> Self {
+        GatePolicy {
+            escalate_pedantic: false,
+            escalate_nursery: false,
+        }
+    }
+
+    /// Also fail on the pedantic cast family and friends, for an on-demand
+    /// numeric-cast audit.
+    pub fn escalate_pedantic() -> Self {
+        GatePolicy {
+            escalate_pedantic: true,
+            escalate_nursery: false,
+        }
+    }
+
+    fn fails_build(&self, category: LintCategory) -> bool {
+        match category {
+            LintCategory::Correctness => true,
+            LintCategory::Pedantic => self.escalate_pedantic,
+            LintCategory::Nursery => self.escalate_nursery,
+            _ => false,
+        }
+    }
+}
+
+/// Outcome of running a policy over a diagnostic set.
+pub struct GateResult {
+    /// `false` if any diagnostic's category fails the build under this policy.
+    pub passed: bool,
+    /// All diagnostics that caused the gate to fail, for reporting.
+    pub blocking: Vec<Diagnostic>,
+}
+
+/// Evaluate `diagnostics` against `policy`.
+pub fn apply_gate(diagnostics: &[Diagnostic], policy: &GatePolicy) -> GateResult {
+    let blocking: Vec<Diagnostic> = diagnostics
+        .iter()
+        .filter(|d| policy.fails_build(d.category))
+        .cloned()
+        .collect();
+    GateResult {
+        passed: blocking.is_empty(),
+        blocking,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::Severity;
+
+    fn diagnostic(category: LintCategory) -> Diagnostic {
+        Diagnostic {
+            lint: "some_lint".to_string(),
+            category,
+            severity: Severity::Warning,
+            file: "src/cast_errors.rs".to_string(),
+            line: 1,
+            column: 1,
+            message: "test".to_string(),
+            byte_start: 0,
+            byte_end: 0,
+            suggestions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn correctness_always_fails_the_gate() {
+        let result = apply_gate(&[diagnostic(LintCategory::Correctness)], &GatePolicy::default_policy());
+        assert!(!result.passed);
+    }
+
+    #[test]
+    fn pedantic_is_silent_by_default() {
+        let result = apply_gate(&[diagnostic(LintCategory::Pedantic)], &GatePolicy::default_policy());
+        assert!(result.passed);
+    }
+
+    #[test]
+    fn pedantic_fails_when_escalated() {
+        let result = apply_gate(&[diagnostic(LintCategory::Pedantic)], &GatePolicy::escalate_pedantic());
+        assert!(!result.passed);
+        assert_eq!(result.blocking.len(), 1);
+    }
+}
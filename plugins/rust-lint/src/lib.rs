@@ -0,0 +1,9 @@
+// rust-lint plugin: runs clippy/rustfmt over a target crate and reports
+// structured results back to the caller.
+
+pub mod autofix;
+pub mod diagnostics;
+pub mod fixture_metadata;
+pub mod float_cmp;
+pub mod gate;
+pub mod profile;
@@ -0,0 +1,160 @@
+//! Lint profiles: the set of extra `-W`/`-D` flags passed to `cargo clippy`,
+//! the same way a real project's CI pins down stricter-than-default lints.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// A named set of extra clippy flags to append to the bare `cargo clippy`
+/// invocation, e.g. `-D rustdoc::broken_intra_doc_links`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LintProfile {
+    pub name: String,
+    pub extra_flags: Vec<String>,
+}
+
+impl LintProfile {
+    /// No extra flags beyond clippy's own defaults.
+    pub fn default_profile() -> Self {
+        LintProfile {
+            name: "default".to_string(),
+            extra_flags: Vec::new(),
+        }
+    }
+
+    /// The flag set a CI pipeline would typically pin down: missing docs,
+    /// doc-link hygiene, and a handful of style lints clippy leaves allow-by-default.
+    pub fn strict() -> Self {
+        LintProfile {
+            name: "strict".to_string(),
+            extra_flags: vec![
+                "-D".to_string(),
+                "rustdoc::broken_intra_doc_links".to_string(),
+                "-W".to_string(),
+                "missing_docs".to_string(),
+                "-W".to_string(),
+                "clippy::missing_docs_in_private_items".to_string(),
+                "-W".to_string(),
+                "clippy::semicolon_if_nothing_returned".to_string(),
+                "-W".to_string(),
+                "clippy::doc_markdown".to_string(),
+                "-W".to_string(),
+                "clippy::manual_let_else".to_string(),
+                "-W".to_string(),
+                "clippy::explicit_iter_loop".to_string(),
+            ],
+        }
+    }
+
+    /// Turns on the pedantic cast lints clippy leaves allow-by-default
+    /// (`cast_possible_truncation`, `cast_possible_wrap`, `cast_precision_loss`,
+    /// `cast_sign_loss`), so they actually appear in the diagnostic stream for
+    /// [`crate::gate::GatePolicy::escalate_pedantic`] to act on.
+    pub fn pedantic_casts() -> Self {
+        LintProfile {
+            name: "pedantic_casts".to_string(),
+            extra_flags: vec![
+                "-W".to_string(),
+                "clippy::cast_possible_truncation".to_string(),
+                "-W".to_string(),
+                "clippy::cast_possible_wrap".to_string(),
+                "-W".to_string(),
+                "clippy::cast_precision_loss".to_string(),
+                "-W".to_string(),
+                "clippy::cast_sign_loss".to_string(),
+            ],
+        }
+    }
+
+    /// Resolve a profile by name, falling back to `default` for anything
+    /// unrecognized.
+    pub fn named(name: &str) -> Self {
+        match name {
+            "strict" => LintProfile::strict(),
+            "pedantic_casts" => LintProfile::pedantic_casts(),
+            _ => LintProfile::default_profile(),
+        }
+    }
+
+    /// Load a profile from a TOML or JSON config file, picked by extension.
+    pub fn from_file(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        let profile = match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => serde_json::from_str(&contents)?,
+            _ => toml::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        };
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_profile_has_no_extra_flags() {
+        assert!(LintProfile::default_profile().extra_flags.is_empty());
+    }
+
+    #[test]
+    fn strict_profile_includes_missing_docs() {
+        let strict = LintProfile::strict();
+        assert!(strict.extra_flags.contains(&"missing_docs".to_string()));
+    }
+
+    #[test]
+    fn named_falls_back_to_default() {
+        assert_eq!(LintProfile::named("nonexistent").name, "default");
+    }
+
+    #[test]
+    fn pedantic_casts_profile_includes_cast_lints() {
+        let pedantic = LintProfile::pedantic_casts();
+        assert!(pedantic.extra_flags.contains(&"clippy::cast_sign_loss".to_string()));
+    }
+
+    /// Write `contents` to a unique scratch file named `name` under the
+    /// system temp dir and clean it up once `body` returns.
+    fn with_scratch_file(name: &str, contents: &str, body: impl FnOnce(&Path)) {
+        let path = std::env::temp_dir().join(format!("rust_lint_profile_test_{}_{name}", std::process::id()));
+        fs::write(&path, contents).expect("failed to write scratch config file");
+        body(&path);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn from_file_reads_a_toml_profile() {
+        with_scratch_file(
+            "profile.toml",
+            "name = \"ci\"\nextra_flags = [\"-W\", \"clippy::pedantic\"]\n",
+            |path| {
+                let profile = LintProfile::from_file(path).expect("valid TOML should load");
+                assert_eq!(profile.name, "ci");
+                assert_eq!(profile.extra_flags, vec!["-W", "clippy::pedantic"]);
+            },
+        );
+    }
+
+    #[test]
+    fn from_file_reads_a_json_profile() {
+        with_scratch_file(
+            "profile.json",
+            r#"{"name": "ci", "extra_flags": ["-W", "clippy::pedantic"]}"#,
+            |path| {
+                let profile = LintProfile::from_file(path).expect("valid JSON should load");
+                assert_eq!(profile.name, "ci");
+                assert_eq!(profile.extra_flags, vec!["-W", "clippy::pedantic"]);
+            },
+        );
+    }
+
+    #[test]
+    fn from_file_errors_on_malformed_config() {
+        with_scratch_file("broken.toml", "this is not valid toml = = =", |path| {
+            assert!(LintProfile::from_file(path).is_err());
+        });
+    }
+}
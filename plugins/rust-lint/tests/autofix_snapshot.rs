@@ -0,0 +1,56 @@
+//! Runs the real `clippy_errors.rs` fixture through
+//! `diagnostics::run_clippy` + `autofix::apply_suggestions`, rustfmt's the
+//! result, and checks it against the hand-maintained `clippy_errors_fixed.rs`
+//! snapshot — so the autofix transformation is verified against an actual
+//! clippy run rather than only the hand-built strings in `autofix.rs`'s unit
+//! tests.
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use rust_lint::autofix::apply_suggestions;
+use rust_lint::diagnostics::run_clippy;
+use rust_lint::profile::LintProfile;
+
+fn rustfmt(source: &str) -> String {
+    let mut child = Command::new("rustfmt")
+        .arg("--emit")
+        .arg("stdout")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("rustfmt must be installed to run this test");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(source.as_bytes())
+        .expect("failed to write source to rustfmt's stdin");
+    let output = child.wait_with_output().expect("failed to wait for rustfmt");
+    String::from_utf8(output.stdout).expect("rustfmt produced non-UTF-8 output")
+}
+
+#[test]
+fn applying_suggestions_to_clippy_errors_matches_the_fixed_snapshot() {
+    let fixtures_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let source_path = fixtures_dir.join("src/clippy_errors.rs");
+    let source = std::fs::read_to_string(&source_path).expect("failed to read clippy_errors.rs");
+
+    let diagnostics = run_clippy(&fixtures_dir, &LintProfile::default_profile())
+        .expect("failed to run cargo clippy over fixtures");
+    let relevant: Vec<_> = diagnostics
+        .into_iter()
+        .filter(|d| d.file.ends_with("clippy_errors.rs"))
+        .collect();
+
+    let fixed = rustfmt(&apply_suggestions(&source, &relevant));
+    let expected =
+        std::fs::read_to_string(fixtures_dir.join("src/clippy_errors_fixed.rs")).expect("failed to read snapshot");
+
+    assert_eq!(
+        fixed, expected,
+        "apply_suggestions output no longer matches clippy_errors_fixed.rs — update the \
+         snapshot if this drift (e.g. a clippy suggestion changing) is intentional"
+    );
+}
@@ -0,0 +1,52 @@
+//! Runs clippy over `tests/fixtures` and checks every fixture still produces
+//! exactly the lints it's declared to produce in `fixture_metadata`. This is
+//! the regression guard against clippy/rustfmt version drift turning a
+//! fixture into dead example code: if a lint starts or stops firing, this
+//! test fails loudly instead of the fixture silently going stale.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use rust_lint::diagnostics::run_clippy;
+use rust_lint::fixture_metadata::EXPECTATIONS;
+use rust_lint::profile::LintProfile;
+
+#[test]
+fn fixtures_trigger_exactly_their_declared_lints() {
+    let fixtures_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    // Each expectation declares the profile it needs (most lints are visible
+    // under "default"; allow-by-default ones need their enabling profile),
+    // so run clippy once per distinct profile rather than once overall.
+    let mut by_profile: HashMap<&str, Vec<_>> = HashMap::new();
+    for expectation in EXPECTATIONS {
+        by_profile.entry(expectation.profile).or_default().push(expectation);
+    }
+
+    for (profile_name, expectations) in by_profile {
+        let profile = LintProfile::named(profile_name);
+        let diagnostics = run_clippy(&fixtures_dir, &profile)
+            .unwrap_or_else(|e| panic!("failed to run cargo clippy under profile {profile_name:?}: {e}"));
+
+        for expectation in expectations {
+            let (start, end) = expectation.line_range;
+            let observed: HashSet<&str> = diagnostics
+                .iter()
+                .filter(|d| d.file.ends_with(expectation.file) && d.line >= start && d.line <= end)
+                .map(|d| d.lint.trim_start_matches("clippy::"))
+                .collect();
+            let expected: HashSet<&str> = expectation
+                .lints
+                .iter()
+                .map(|l| l.trim_start_matches("clippy::"))
+                .collect();
+
+            assert_eq!(
+                observed, expected,
+                "{} ({}, profile {:?}) expected lints {:?} but clippy reported {:?} — run the \
+                 fixture audit and update fixture_metadata::EXPECTATIONS if this drift is intentional",
+                expectation.item, expectation.file, profile_name, expected, observed
+            );
+        }
+    }
+}
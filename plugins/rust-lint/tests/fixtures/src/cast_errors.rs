@@ -0,0 +1,23 @@
+// File exercising the allow-by-default pedantic cast lints. These are
+// silent under `cargo clippy` alone; see profile::LintProfile::pedantic_casts
+// for turning them on per run and gate::GatePolicy for failing on them.
+
+/// Narrowing cast: triggers clippy::cast_possible_truncation.
+pub fn narrowing_cast(x: u32) -> u8 {
+    x as u8
+}
+
+/// Sign-changing cast of a same-width type: triggers clippy::cast_possible_wrap.
+pub fn wrapping_cast(x: u32) -> i32 {
+    x as i32
+}
+
+/// Integer-to-float cast that can lose precision: triggers clippy::cast_precision_loss.
+pub fn precision_losing_cast(x: u64) -> f32 {
+    x as f32
+}
+
+/// Signed-to-unsigned cast: triggers clippy::cast_sign_loss.
+pub fn sign_losing_cast(x: i32) -> u32 {
+    x as u32
+}
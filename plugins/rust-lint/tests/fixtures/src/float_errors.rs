@@ -0,0 +1,25 @@
+// File with direct float equality comparisons for testing clippy::float_cmp
+
+pub const ONE: f32 = 1.0;
+pub const TWO: f32 = 2.0;
+
+/// Direct float literal equality: triggers clippy::float_cmp.
+pub fn float_literal_eq() -> bool {
+    1f32 == ONE
+}
+
+/// Computed float equality: triggers clippy::float_cmp.
+pub fn float_sum_eq() -> bool {
+    ONE + ONE == TWO
+}
+
+/// Float compared against zero is exempt from clippy::float_cmp, so this is
+/// NOT expected to be flagged.
+pub fn float_ne_zero() -> bool {
+    ONE != 0f32
+}
+
+/// Ordering comparisons are never flagged, only `==`/`!=`.
+pub fn float_less_than(x: f32, y: f32) -> bool {
+    x < y
+}
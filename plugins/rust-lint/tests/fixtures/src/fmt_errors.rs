@@ -13,7 +13,7 @@ pub fn long_line() -> String {
 pub fn weird_braces()
 {
 let x=5;let y=10;
-x+y
+x+y;
 }
 
 pub struct BadlyFormattedStruct{pub field1:i32,pub field2:String,pub field3:bool}
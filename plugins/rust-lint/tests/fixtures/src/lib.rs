@@ -1,10 +1,19 @@
 // Test fixtures library for rust-lint plugin testing
 
+pub mod cast_errors;
 pub mod clean;
 pub mod clippy_errors;
+// Corrected snapshot of clippy_errors; not glob re-exported since its function
+// names intentionally shadow the dirty originals.
+pub mod clippy_errors_fixed;
+pub mod float_errors;
 pub mod fmt_errors;
+pub mod strict_profile_errors;
 
 // Re-export for convenience
+pub use cast_errors::*;
 pub use clean::*;
 pub use clippy_errors::*;
+pub use float_errors::*;
 pub use fmt_errors::*;
+pub use strict_profile_errors::*;
@@ -0,0 +1,30 @@
+// File with issues that only the "strict" lint profile flags
+
+/// Public function, documented, so it won't trip missing_docs_in_private_items.
+pub fn documented(x: i32) -> i32 {
+    x
+}
+
+// Private item with no doc comment: triggers clippy::missing_docs_in_private_items
+// under the strict profile.
+fn undocumented_private(x: i32) -> i32 {
+    x * 2
+}
+
+/// Matches that should be a `let ... else` (clippy::manual_let_else).
+pub fn manual_let_else_example(opt: Option<i32>) -> i32 {
+    let n = match opt {
+        Some(n) => n,
+        None => return -1,
+    };
+    n + 1
+}
+
+/// Explicit `.iter()` call where `for x in &values` would do (clippy::explicit_iter_loop).
+pub fn explicit_iter_loop_example(values: &[i32]) -> i32 {
+    let mut sum = 0;
+    for v in values.iter() {
+        sum += v;
+    }
+    sum
+}